@@ -40,6 +40,10 @@ pub enum Error {
     VerificationFailed = 7,
     VkNotSet = 8,
     AlreadyRevealed = 9,
+    TimeoutNotElapsed = 10,
+    NoForfeitCondition = 11,
+    InvalidConfig = 12,
+    ConfigNotSet = 13,
 }
 
 #[contracttype]
@@ -55,6 +59,30 @@ pub struct Game {
     pub p2_score: u32,
     pub current_round: u32,
     pub is_complete: bool,
+    pub round_started_ledger: u32,
+    pub both_committed_ledger: Option<u32>,
+    pub circuit_id: u32,
+}
+
+/// Per-game economics: entry fee, round count, and payoff matrix.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub entry_fee: i128,
+    pub total_rounds: u32,
+    pub payoff_cc: (u32, u32),
+    pub payoff_cd: (u32, u32),
+    pub payoff_dc: (u32, u32),
+    pub payoff_dd: (u32, u32),
+    pub move_space: u32,
+}
+
+/// A registered circuit's verifying key and expected proof byte length.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitConfig {
+    pub vk: Bytes,
+    pub proof_len: u32,
 }
 
 #[contracttype]
@@ -63,30 +91,189 @@ pub enum DataKey {
     Game(u32),
     GameHubAddress,
     Admin,
-    VerificationKey,
+    VerificationKey(u32),
     NativeToken,
     Treasury,
+    CommitWindowLedgers,
+    RevealWindowLedgers,
+    GameConfig(u32),
+    DefaultConfig,
+    RakeBps,
+    Rating(Address),
 }
 
 const GAME_TTL_LEDGERS: u32 = 518_400;
 
+// Basis-point denominator for the treasury rake (e.g. 500 = 5%).
+const RAKE_DENOM_BPS: i128 = 10_000;
+
+// Persistent ratings should outlive any single game's temporary storage.
+const RATING_TTL_LEDGERS: u32 = 3_110_400;
+
+const STARTING_RATING: i32 = 1200;
+const ELO_K: i32 = 32;
+
+// Expected-score lookup table (E * 1000) for rating differences 0, 50, ..., 400.
+const ELO_EXPECTED_TABLE: [i32; 9] = [500, 571, 640, 703, 760, 808, 849, 882, 909];
+
+// The circuit id under which the constructor registers its verifying key.
+const DEFAULT_CIRCUIT_ID: u32 = 0;
+
 #[contract]
 pub struct ZkGameTheoryContract;
 
 #[contractimpl]
 impl ZkGameTheoryContract {
-    pub fn __constructor(env: Env, admin: Address, game_hub: Address, vk: Bytes, native_token: Address, treasury: Address) {
+    pub fn __constructor(
+        env: Env,
+        admin: Address,
+        game_hub: Address,
+        vk: Bytes,
+        native_token: Address,
+        treasury: Address,
+        commit_window_ledgers: u32,
+        reveal_window_ledgers: u32,
+    ) {
         env.storage().instance().set(&DataKey::Admin, &admin);
         env.storage().instance().set(&DataKey::GameHubAddress, &game_hub);
-        env.storage().instance().set(&DataKey::VerificationKey, &vk);
+        let default_circuit = CircuitConfig { vk, proof_len: 14592 };
+        env.storage().instance().set(&DataKey::VerificationKey(DEFAULT_CIRCUIT_ID), &default_circuit);
         env.storage().instance().set(&DataKey::NativeToken, &native_token);
         env.storage().instance().set(&DataKey::Treasury, &treasury);
+        env.storage().instance().set(&DataKey::CommitWindowLedgers, &commit_window_ledgers);
+        env.storage().instance().set(&DataKey::RevealWindowLedgers, &reveal_window_ledgers);
+
+        let default_config = GameConfig {
+            entry_fee: 100_000_000,
+            total_rounds: 5,
+            payoff_cc: (3, 3),
+            payoff_cd: (0, 5),
+            payoff_dc: (5, 0),
+            payoff_dd: (1, 1),
+            move_space: 2,
+        };
+        env.storage().instance().set(&DataKey::DefaultConfig, &default_config);
+        env.storage().instance().set(&DataKey::RakeBps, &500i128);
+    }
+
+    /// Admin can retune the treasury rake, in basis points (500 = 5%).
+    pub fn set_rake_bps(env: Env, rake_bps: i128) -> Result<(), Error> {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
+        admin.require_auth();
+        if rake_bps < 0 || rake_bps > RAKE_DENOM_BPS {
+            return Err(Error::InvalidConfig);
+        }
+        env.storage().instance().set(&DataKey::RakeBps, &rake_bps);
+        Ok(())
     }
 
-    pub fn set_verification_key(env: Env, vk: Bytes) {
+    /// Admin can retune the default match economics for games started after this call.
+    pub fn set_default_config(env: Env, config: GameConfig) -> Result<(), Error> {
         let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
         admin.require_auth();
-        env.storage().instance().set(&DataKey::VerificationKey, &vk);
+        Self::validate_config(&config)?;
+        env.storage().instance().set(&DataKey::DefaultConfig, &config);
+        Ok(())
+    }
+
+    fn elo_expected_x1000(rating_diff: i32) -> i32 {
+        let clamped = rating_diff.clamp(-400, 400);
+        let negative = clamped < 0;
+        let abs_diff = clamped.unsigned_abs() as i32;
+
+        let idx = (abs_diff / 50) as usize;
+        let rem = abs_diff % 50;
+        let lo = ELO_EXPECTED_TABLE[idx];
+        let hi = ELO_EXPECTED_TABLE[(idx + 1).min(ELO_EXPECTED_TABLE.len() - 1)];
+        let interpolated = lo + (hi - lo) * rem / 50;
+
+        if negative { 1000 - interpolated } else { interpolated }
+    }
+
+    fn elo_update(rating: i32, opponent_rating: i32, result_x1000: i32) -> i32 {
+        let expected = Self::elo_expected_x1000(rating - opponent_rating);
+        let delta = ELO_K * (result_x1000 - expected) / 1000;
+        (rating + delta).max(100)
+    }
+
+    /// Updates both players' persistent Elo ratings; results are win=1000, draw=500, loss=0.
+    fn apply_rating_update(env: &Env, player1: &Address, player2: &Address, p1_result_x1000: i32, p2_result_x1000: i32) {
+        let p1_rating_key = DataKey::Rating(player1.clone());
+        let p2_rating_key = DataKey::Rating(player2.clone());
+        let p1_rating: i32 = env.storage().persistent().get(&p1_rating_key).unwrap_or(STARTING_RATING);
+        let p2_rating: i32 = env.storage().persistent().get(&p2_rating_key).unwrap_or(STARTING_RATING);
+
+        let new_p1_rating = Self::elo_update(p1_rating, p2_rating, p1_result_x1000);
+        let new_p2_rating = Self::elo_update(p2_rating, p1_rating, p2_result_x1000);
+        env.storage().persistent().set(&p1_rating_key, &new_p1_rating);
+        env.storage().persistent().set(&p2_rating_key, &new_p2_rating);
+        env.storage().persistent().extend_ttl(&p1_rating_key, RATING_TTL_LEDGERS, RATING_TTL_LEDGERS);
+        env.storage().persistent().extend_ttl(&p2_rating_key, RATING_TTL_LEDGERS, RATING_TTL_LEDGERS);
+    }
+
+    /// Splits `total_pool` into (rake, p1_payout, p2_payout), proportional to score.
+    fn compute_payout_split(total_pool: i128, rake_bps: i128, p1_score: u32, p2_score: u32) -> (i128, i128, i128) {
+        let rake = total_pool * rake_bps / RAKE_DENOM_BPS;
+        let remaining = total_pool - rake;
+
+        let p1_score = p1_score as i128;
+        let p2_score = p2_score as i128;
+        let (p1_payout, p2_payout) = if p1_score == 0 && p2_score == 0 {
+            let half = remaining / 2;
+            (half, remaining - half)
+        } else if p1_score >= p2_score {
+            let p2_payout = remaining * p2_score / (p1_score + p2_score);
+            (remaining - p2_payout, p2_payout)
+        } else {
+            let p1_payout = remaining * p1_score / (p1_score + p2_score);
+            (p1_payout, remaining - p1_payout)
+        };
+
+        (rake, p1_payout, p2_payout)
+    }
+
+    /// Whether the opponent has stalled past the commit or reveal deadline for the round.
+    fn is_opponent_stalled(
+        claimant_committed: bool,
+        opponent_committed: bool,
+        claimant_revealed: bool,
+        opponent_revealed: bool,
+        commit_elapsed: u32,
+        commit_window: u32,
+        reveal_elapsed: u32,
+        reveal_window: u32,
+    ) -> bool {
+        if claimant_committed && !opponent_committed {
+            commit_elapsed >= commit_window
+        } else if claimant_revealed && !opponent_revealed && claimant_committed && opponent_committed {
+            reveal_elapsed >= reveal_window
+        } else {
+            false
+        }
+    }
+
+    fn validate_config(config: &GameConfig) -> Result<(), Error> {
+        // Only the 2x2 Cooperate/Defect payoff matrix is supported.
+        if config.entry_fee <= 0 || config.total_rounds == 0 || config.move_space != 2 {
+            return Err(Error::InvalidConfig);
+        }
+        Ok(())
+    }
+
+    /// Admin can retune the commit/reveal liveness windows without redeploying.
+    pub fn set_timeout_windows(env: Env, commit_window_ledgers: u32, reveal_window_ledgers: u32) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::CommitWindowLedgers, &commit_window_ledgers);
+        env.storage().instance().set(&DataKey::RevealWindowLedgers, &reveal_window_ledgers);
+    }
+
+    /// Register (or update) the verifying key and expected proof length for a circuit.
+    pub fn register_circuit(env: Env, circuit_id: u32, vk: Bytes, proof_len: u32) {
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).expect("Admin not set");
+        admin.require_auth();
+        let circuit = CircuitConfig { vk, proof_len };
+        env.storage().instance().set(&DataKey::VerificationKey(circuit_id), &circuit);
     }
 
     pub fn start_game(
@@ -96,21 +283,27 @@ impl ZkGameTheoryContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        circuit_id: u32,
     ) -> Result<(), Error> {
         if player1 == player2 {
             panic!("Cannot play against yourself");
         }
 
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
+        if !env.storage().instance().has(&DataKey::VerificationKey(circuit_id)) {
+            return Err(Error::VkNotSet);
+        }
+
+        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env), circuit_id.into_val(&env)]);
+        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env), circuit_id.into_val(&env)]);
 
         let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).expect("NativeToken not set");
         let token_client = token::Client::new(&env, &native_token);
-        
-        // Transfer 10 XLM = 100_000_000 stroops from each player
-        let entry_fee = 100_000_000i128;
-        token_client.transfer(&player1, &env.current_contract_address(), &entry_fee);
-        token_client.transfer(&player2, &env.current_contract_address(), &entry_fee);
+
+        let config: GameConfig = env.storage().instance().get(&DataKey::DefaultConfig).ok_or(Error::ConfigNotSet)?;
+        Self::validate_config(&config)?;
+
+        token_client.transfer(&player1, &env.current_contract_address(), &config.entry_fee);
+        token_client.transfer(&player2, &env.current_contract_address(), &config.entry_fee);
 
         let game_hub_addr: Address = env.storage().instance().get(&DataKey::GameHubAddress).expect("GameHub address not set");
         let game_hub = GameHubClient::new(&env, &game_hub_addr);
@@ -135,12 +328,19 @@ impl ZkGameTheoryContract {
             p2_score: 0,
             current_round: 1,
             is_complete: false,
+            round_started_ledger: env.ledger().sequence(),
+            both_committed_ledger: None,
+            circuit_id,
         };
 
         let game_key = DataKey::Game(session_id);
         env.storage().temporary().set(&game_key, &game);
         env.storage().temporary().extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
 
+        let config_key = DataKey::GameConfig(session_id);
+        env.storage().temporary().set(&config_key, &config);
+        env.storage().temporary().extend_ttl(&config_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+
         Ok(())
     }
 
@@ -167,6 +367,10 @@ impl ZkGameTheoryContract {
             return Err(Error::NotPlayer);
         }
 
+        if game.p1_commitments.contains_key(round) && game.p2_commitments.contains_key(round) {
+            game.both_committed_ledger = Some(env.ledger().sequence());
+        }
+
         env.storage().temporary().set(&key, &game);
         Ok(())
     }
@@ -200,24 +404,37 @@ impl ZkGameTheoryContract {
             return Err(Error::NotPlayer);
         };
 
-        // ZK Verification
-        let vk_bytes: Bytes = env.storage().instance().get(&DataKey::VerificationKey).ok_or(Error::VkNotSet)?;
-        let verifier = UltraHonkVerifier::new(&env, &vk_bytes).map_err(|_| Error::VerificationFailed)?;
-        
-        // Public inputs: [commitment]
+        let config: GameConfig = env.storage().temporary().get(&DataKey::GameConfig(session_id)).ok_or(Error::ConfigNotSet)?;
+        if move_val >= config.move_space {
+            return Err(Error::InvalidMove);
+        }
+
+        // ZK Verification against the circuit this game was started with
+        let circuit: CircuitConfig = env.storage().instance().get(&DataKey::VerificationKey(game.circuit_id)).ok_or(Error::VkNotSet)?;
+        let verifier = UltraHonkVerifier::new(&env, &circuit.vk).map_err(|_| Error::VerificationFailed)?;
+
+        // Public inputs: [commitment, move_val] - the circuit proves
+        // commitment == hash(move_val, salt), binding the verified proof to the
+        // move being recorded below. move_val is serialized as a field-sized
+        // (32-byte) big-endian input to match the circuit's field element layout.
+        let mut move_val_field = [0u8; 32];
+        move_val_field[28..].copy_from_slice(&move_val.to_be_bytes());
+
         let mut public_inputs = Bytes::new(&env);
         public_inputs.append(&commitment.clone().into());
+        public_inputs.append(&Bytes::from_array(&env, &move_val_field));
 
         // Proof Slicing
         let proof_len = proof.len();
-        let actual_proof = if proof_len == 14592 {
+        let expected_proof_len = circuit.proof_len;
+        let actual_proof = if proof_len == expected_proof_len {
             proof
-        } else if proof_len > 14592 {
-            proof.slice((proof_len - 14592)..)
+        } else if proof_len > expected_proof_len {
+            proof.slice((proof_len - expected_proof_len)..)
         } else {
             proof
         };
-        
+
         verifier.verify(&actual_proof, &public_inputs).map_err(|_| Error::VerificationFailed)?;
 
         // Update move
@@ -233,19 +450,24 @@ impl ZkGameTheoryContract {
             let m2 = game.p2_moves.get(round).unwrap();
 
             // 0: Cooperate, 1: Defect
-            match (m1, m2) {
-                (0, 0) => { game.p1_score += 3; game.p2_score += 3; },
-                (1, 1) => { game.p1_score += 1; game.p2_score += 1; },
-                (0, 1) => { game.p1_score += 0; game.p2_score += 5; },
-                (1, 0) => { game.p1_score += 5; game.p2_score += 0; },
+            let (p1_points, p2_points) = match (m1, m2) {
+                (0, 0) => config.payoff_cc,
+                (1, 1) => config.payoff_dd,
+                (0, 1) => config.payoff_cd,
+                (1, 0) => config.payoff_dc,
                 _ => panic!("Invalid move state"),
             };
+            game.p1_score += p1_points;
+            game.p2_score += p2_points;
 
-            if game.current_round == 5 {
+            if game.current_round == config.total_rounds {
                 game.is_complete = true;
-                Self::finalize_game_internal(&env, session_id, &game)?;
+                env.storage().temporary().set(&key, &game);
+                return Self::finalize_game_internal(&env, session_id, &game);
             } else {
                 game.current_round += 1;
+                game.round_started_ledger = env.ledger().sequence();
+                game.both_committed_ledger = None;
             }
         }
 
@@ -254,15 +476,91 @@ impl ZkGameTheoryContract {
         Ok(())
     }
 
+    /// Claim forfeit of the pot when the opponent has stalled past the commit/reveal deadline.
+    pub fn claim_timeout(env: Env, session_id: u32, claimant: Address) -> Result<(), Error> {
+        claimant.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env.storage().temporary().get(&key).ok_or(Error::GameNotFound)?;
+
+        if game.is_complete {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let claimant_is_p1 = if claimant == game.player1 {
+            true
+        } else if claimant == game.player2 {
+            false
+        } else {
+            return Err(Error::NotPlayer);
+        };
+
+        let round = game.current_round;
+        let now = env.ledger().sequence();
+        let commit_elapsed = now.saturating_sub(game.round_started_ledger);
+
+        let (claimant_committed, opponent_committed) = if claimant_is_p1 {
+            (game.p1_commitments.contains_key(round), game.p2_commitments.contains_key(round))
+        } else {
+            (game.p2_commitments.contains_key(round), game.p1_commitments.contains_key(round))
+        };
+        let (claimant_revealed, opponent_revealed) = if claimant_is_p1 {
+            (game.p1_moves.contains_key(round), game.p2_moves.contains_key(round))
+        } else {
+            (game.p2_moves.contains_key(round), game.p1_moves.contains_key(round))
+        };
+
+        let commit_window: u32 = env.storage().instance().get(&DataKey::CommitWindowLedgers).unwrap_or(0);
+        let reveal_window: u32 = env.storage().instance().get(&DataKey::RevealWindowLedgers).unwrap_or(0);
+        let both_committed_ledger = game.both_committed_ledger.unwrap_or(game.round_started_ledger);
+        let reveal_elapsed = now.saturating_sub(both_committed_ledger);
+
+        let opponent_stalled = Self::is_opponent_stalled(
+            claimant_committed,
+            opponent_committed,
+            claimant_revealed,
+            opponent_revealed,
+            commit_elapsed,
+            commit_window,
+            reveal_elapsed,
+            reveal_window,
+        );
+
+        if !opponent_stalled {
+            if (claimant_committed && !opponent_committed) || (claimant_revealed && !opponent_revealed) {
+                return Err(Error::TimeoutNotElapsed);
+            }
+            return Err(Error::NoForfeitCondition);
+        }
+
+        game.is_complete = true;
+        env.storage().temporary().set(&key, &game);
+
+        let config: GameConfig = env.storage().temporary().get(&DataKey::GameConfig(session_id)).ok_or(Error::ConfigNotSet)?;
+        let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
+        let token_client = token::Client::new(&env, &native_token);
+        let pool = config.entry_fee * 2;
+        token_client.transfer(&env.current_contract_address(), &claimant, &pool);
+
+        let (p1_result, p2_result) = if claimant_is_p1 { (1000, 0) } else { (0, 1000) };
+        Self::apply_rating_update(&env, &game.player1, &game.player2, p1_result, p2_result);
+
+        let game_hub_addr: Address = env.storage().instance().get(&DataKey::GameHubAddress).expect("GameHub address not set");
+        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        game_hub.end_game(&session_id, &claimant_is_p1);
+
+        Ok(())
+    }
+
     fn finalize_game_internal(env: &Env, session_id: u32, game: &Game) -> Result<(), Error> {
+        let config: GameConfig = env.storage().temporary().get(&DataKey::GameConfig(session_id)).ok_or(Error::ConfigNotSet)?;
         let native_token: Address = env.storage().instance().get(&DataKey::NativeToken).unwrap();
         let treasury: Address = env.storage().instance().get(&DataKey::Treasury).unwrap();
         let token_client = token::Client::new(env, &native_token);
 
-        // 1 point = 0.3 XLM = 3_000_000 stroops
-        let point_value = 3_000_000i128;
-        let p1_payout = (game.p1_score as i128) * point_value;
-        let p2_payout = (game.p2_score as i128) * point_value;
+        let rake_bps: i128 = env.storage().instance().get(&DataKey::RakeBps).unwrap_or(0);
+        let total_pool = config.entry_fee * 2;
+        let (rake, p1_payout, p2_payout) = Self::compute_payout_split(total_pool, rake_bps, game.p1_score, game.p2_score);
 
         let contract_address = env.current_contract_address();
 
@@ -272,17 +570,21 @@ impl ZkGameTheoryContract {
         if p2_payout > 0 {
             token_client.transfer(&contract_address, &game.player2, &p2_payout);
         }
-
-        // Send remaining balance to treasury (entry was 20 XLM = 200_000_000)
-        let total_paid = p1_payout + p2_payout;
-        let total_pool = 200_000_000i128;
-        if total_pool > total_paid {
-            let treasury_payout = total_pool - total_paid;
-            token_client.transfer(&contract_address, &treasury, &treasury_payout);
+        if rake > 0 {
+            token_client.transfer(&contract_address, &treasury, &rake);
         }
 
         let player1_won = game.p1_score >= game.p2_score;
 
+        let (p1_result, p2_result) = if game.p1_score > game.p2_score {
+            (1000, 0)
+        } else if game.p1_score < game.p2_score {
+            (0, 1000)
+        } else {
+            (500, 500)
+        };
+        Self::apply_rating_update(env, &game.player1, &game.player2, p1_result, p2_result);
+
         let game_hub_addr: Address = env.storage().instance().get(&DataKey::GameHubAddress).expect("GameHub address not set");
         let game_hub = GameHubClient::new(env, &game_hub_addr);
         game_hub.end_game(&session_id, &player1_won);
@@ -290,6 +592,10 @@ impl ZkGameTheoryContract {
         Ok(())
     }
 
+    pub fn get_rating(env: Env, player: Address) -> i32 {
+        env.storage().persistent().get(&DataKey::Rating(player)).unwrap_or(STARTING_RATING)
+    }
+
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
         let key = DataKey::Game(session_id);
         env.storage().temporary().get(&key).ok_or(Error::GameNotFound)
@@ -305,3 +611,97 @@ impl ZkGameTheoryContract {
         env.deployer().update_current_contract_wasm(new_wasm_hash);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payout_split_blowout_pays_winner_after_rake() {
+        let (rake, p1, p2) = ZkGameTheoryContract::compute_payout_split(200_000_000, 500, 14, 0);
+        assert_eq!(rake, 10_000_000);
+        assert_eq!(p1, 190_000_000);
+        assert_eq!(p2, 0);
+        assert_eq!(rake + p1 + p2, 200_000_000);
+    }
+
+    #[test]
+    fn payout_split_tie_splits_remaining_evenly() {
+        let (rake, p1, p2) = ZkGameTheoryContract::compute_payout_split(200_000_000, 500, 9, 9);
+        assert_eq!(rake, 10_000_000);
+        assert_eq!(p1, 95_000_000);
+        assert_eq!(p2, 95_000_000);
+        assert_eq!(rake + p1 + p2, 200_000_000);
+    }
+
+    #[test]
+    fn payout_split_zero_zero_splits_50_50() {
+        let (rake, p1, p2) = ZkGameTheoryContract::compute_payout_split(200_000_000, 500, 0, 0);
+        assert_eq!(rake, 10_000_000);
+        assert_eq!(p1, 95_000_000);
+        assert_eq!(p2, 95_000_000);
+        assert_eq!(rake + p1 + p2, 200_000_000);
+    }
+
+    #[test]
+    fn payout_split_uneven_remainder_goes_to_higher_scorer() {
+        let (rake, p1, p2) = ZkGameTheoryContract::compute_payout_split(100, 0, 1, 2);
+        assert_eq!(p1, 33);
+        assert_eq!(p2, 67);
+        assert_eq!(rake + p1 + p2, 100);
+    }
+
+    #[test]
+    fn elo_expected_is_half_at_zero_diff() {
+        assert_eq!(ZkGameTheoryContract::elo_expected_x1000(0), 500);
+    }
+
+    #[test]
+    fn elo_expected_saturates_beyond_table_range() {
+        assert_eq!(ZkGameTheoryContract::elo_expected_x1000(1000), ZkGameTheoryContract::elo_expected_x1000(400));
+        assert_eq!(ZkGameTheoryContract::elo_expected_x1000(-1000), ZkGameTheoryContract::elo_expected_x1000(-400));
+    }
+
+    #[test]
+    fn elo_expected_is_symmetric() {
+        let diff = 150;
+        assert_eq!(
+            ZkGameTheoryContract::elo_expected_x1000(diff) + ZkGameTheoryContract::elo_expected_x1000(-diff),
+            1000
+        );
+    }
+
+    #[test]
+    fn elo_update_winner_gains_loser_loses() {
+        let winner = ZkGameTheoryContract::elo_update(1200, 1200, 1000);
+        let loser = ZkGameTheoryContract::elo_update(1200, 1200, 0);
+        assert!(winner > 1200);
+        assert!(loser < 1200);
+    }
+
+    #[test]
+    fn elo_update_clamps_to_minimum_rating() {
+        let rating = ZkGameTheoryContract::elo_update(100, 2000, 0);
+        assert_eq!(rating, 100);
+    }
+
+    #[test]
+    fn stall_not_flagged_before_commit_window_elapses() {
+        assert!(!ZkGameTheoryContract::is_opponent_stalled(true, false, false, false, 5, 10, 0, 10));
+    }
+
+    #[test]
+    fn stall_flagged_once_commit_window_elapses() {
+        assert!(ZkGameTheoryContract::is_opponent_stalled(true, false, false, false, 10, 10, 0, 10));
+    }
+
+    #[test]
+    fn stall_flagged_once_reveal_window_elapses_after_both_committed() {
+        assert!(ZkGameTheoryContract::is_opponent_stalled(true, true, true, false, 3, 10, 20, 10));
+    }
+
+    #[test]
+    fn stall_not_flagged_when_nobody_is_ahead() {
+        assert!(!ZkGameTheoryContract::is_opponent_stalled(false, false, false, false, 100, 10, 100, 10));
+    }
+}